@@ -3,9 +3,13 @@ use std::{
     str::{from_utf8, Utf8Error},
 };
 
+use base64::engine::general_purpose;
+use base64::Engine as _;
 use crc::{Crc, CRC_32_ISO_HDLC};
 
 use crate::chunk_type::ChunkType;
+use crate::crypto;
+use crate::signature;
 
 pub struct Chunk {
     data_size: u32,
@@ -14,10 +18,12 @@ pub struct Chunk {
     crc: u32,
 }
 
-pub fn crc(bytes: &[u8]) -> u32 {
+pub fn crc_of(parts: &[&[u8]]) -> u32 {
     let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC);
     let mut digest = crc.digest();
-    digest.update(bytes);
+    for part in parts {
+        digest.update(part);
+    }
     digest.finalize()
 }
 
@@ -39,48 +45,54 @@ impl TryFrom<&[u8]> for Chunk {
             return Err("Not enough data to build chunk".into());
         }
         let data_size = u32::from_be_bytes(data[0..4].try_into().unwrap()) as usize;
-        let chunk_type_data: [u8; 4] = data[4..8].try_into().unwrap();
-        let chunk_type = ChunkType::try_from(chunk_type_data);
-        if let Err(msg) = chunk_type {
-            return Err(msg);
+        if data.len() < 12 + data_size {
+            return Err("Not enough data to build chunk".into());
         }
-        let chunk_type = chunk_type.unwrap();
+        let chunk_type_data: [u8; 4] = data[4..8].try_into().unwrap();
+        let chunk_type = ChunkType::try_from(chunk_type_data)?;
         let chunk_data: Vec<u8> = data[8..(8 + data_size)].into();
-        let valid_crc = crc(chunk_type
-            .bytes()
-            .iter()
-            .chain(chunk_data.iter())
-            .copied()
-            .collect::<Vec<u8>>()
-            .as_slice());
-        let crc = u32::from_be_bytes(data[(8 + data_size)..].try_into().unwrap());
+        let crc = u32::from_be_bytes(data[(8 + data_size)..(12 + data_size)].try_into().unwrap());
+        Chunk::from_parts(chunk_type, chunk_data, crc)
+    }
+}
+
+impl Chunk {
+    pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
+        let crc = crc_of(&[&chunk_type.bytes(), &data]);
+        Chunk {
+            chunk_type,
+            data_size: data.len() as u32,
+            chunk_data: data,
+            crc,
+        }
+    }
+
+    pub(crate) fn from_parts(
+        chunk_type: ChunkType,
+        chunk_data: Vec<u8>,
+        crc: u32,
+    ) -> Result<Self, String> {
+        let valid_crc = crc_of(&[&chunk_type.bytes(), &chunk_data]);
         if crc != valid_crc {
             return Err(format!("Invalid crc, expected {valid_crc}, found {crc}"));
         }
         Ok(Chunk {
-            data_size: data_size as u32,
+            data_size: chunk_data.len() as u32,
             chunk_type,
             chunk_data,
             crc,
         })
     }
-}
 
-impl Chunk {
-    pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
-        let crc = crc(chunk_type
-            .bytes()
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.data_size
+            .to_be_bytes()
             .iter()
-            .chain(data.iter())
+            .chain(self.chunk_type.bytes().iter())
+            .chain(self.chunk_data.iter())
+            .chain(self.crc.to_be_bytes().iter())
             .copied()
-            .collect::<Vec<u8>>()
-            .as_slice());
-        Chunk {
-            chunk_type,
-            data_size: data.len() as u32,
-            chunk_data: data,
-            crc,
-        }
+            .collect()
     }
 
     pub const fn crc(&self) -> u32 {
@@ -98,6 +110,59 @@ impl Chunk {
     pub fn data_as_string(&self) -> Result<&str, Utf8Error> {
         from_utf8(&self.chunk_data.as_slice())
     }
+
+    pub fn data(&self) -> &[u8] {
+        &self.chunk_data
+    }
+
+    pub fn data_as_base64(&self) -> String {
+        general_purpose::STANDARD.encode(&self.chunk_data)
+    }
+
+    pub fn data_as_hex(&self) -> String {
+        hex::encode(&self.chunk_data)
+    }
+
+    pub fn from_base64(chunk_type: ChunkType, data: &str) -> Result<Self, String> {
+        let chunk_data = general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| e.to_string())?;
+        Ok(Self::new(chunk_type, chunk_data))
+    }
+
+    pub fn from_hex(chunk_type: ChunkType, data: &str) -> Result<Self, String> {
+        let chunk_data = hex::decode(data).map_err(|e| e.to_string())?;
+        Ok(Self::new(chunk_type, chunk_data))
+    }
+
+    pub fn new_encrypted(
+        chunk_type: ChunkType,
+        plaintext: Vec<u8>,
+        passphrase: &str,
+    ) -> Result<Self, String> {
+        let chunk_data = crypto::encrypt(&plaintext, passphrase)?;
+        Ok(Self::new(chunk_type, chunk_data))
+    }
+
+    pub fn decrypt(&self, passphrase: &str) -> Result<Vec<u8>, String> {
+        crypto::decrypt(&self.chunk_data, passphrase)
+    }
+
+    pub fn sign(&self, secret_key: &secp256k1::SecretKey) -> [u8; 64] {
+        signature::sign(self, secret_key)
+    }
+
+    pub fn verify(
+        &self,
+        signature: &[u8; 64],
+        public_key: &secp256k1::PublicKey,
+    ) -> Result<(), String> {
+        signature::verify(self, signature, public_key)
+    }
+
+    pub fn signature_chunk(signature: &[u8; 64]) -> Chunk {
+        signature::signature_chunk(signature)
+    }
 }
 
 #[cfg(test)]
@@ -208,6 +273,15 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_oversized_length_does_not_panic() {
+        let chunk_data: Vec<u8> = vec![0xff, 0xff, 0xff, 0xff, b'R', b'u', b'S', b't', 0, 0, 0, 0];
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+
+        assert!(chunk.is_err());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
@@ -228,4 +302,48 @@ mod tests {
 
         let _chunk_string = format!("{}", chunk);
     }
+
+    #[test]
+    fn test_new_encrypted_chunk_round_trips() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let plaintext = "This is where your secret message will be!".as_bytes().to_vec();
+        let chunk = Chunk::new_encrypted(chunk_type, plaintext.clone(), "correct horse").unwrap();
+
+        assert_ne!(chunk.data(), plaintext.as_slice());
+        assert_eq!(chunk.decrypt("correct horse").unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_passphrase() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let plaintext = "This is where your secret message will be!".as_bytes().to_vec();
+        let chunk = Chunk::new_encrypted(chunk_type, plaintext, "correct horse").unwrap();
+
+        assert!(chunk.decrypt("wrong passphrase").is_err());
+    }
+
+    #[test]
+    fn test_base64_round_trip_for_non_utf8_data() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = vec![0xff, 0x00, 0x10, 0x42];
+        let chunk = Chunk::new(chunk_type, data.clone());
+        assert!(chunk.data_as_string().is_err());
+
+        let encoded = chunk.data_as_base64();
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let decoded = Chunk::from_base64(chunk_type, &encoded).unwrap();
+        assert_eq!(decoded.data_as_base64(), encoded);
+    }
+
+    #[test]
+    fn test_hex_round_trip_for_non_utf8_data() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = vec![0xff, 0x00, 0x10, 0x42];
+        let chunk = Chunk::new(chunk_type, data);
+
+        let encoded = chunk.data_as_hex();
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let decoded = Chunk::from_hex(chunk_type, &encoded).unwrap();
+        assert_eq!(decoded.data_as_hex(), encoded);
+    }
 }
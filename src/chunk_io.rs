@@ -0,0 +1,167 @@
+use std::io::{self, Read, Write};
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+/// PNG chunk length is a 4-byte unsigned value but the spec caps it at
+/// 2^31-1; we cap well below that so a corrupt or adversarial header can't
+/// force a multi-gigabyte allocation before the length is even validated.
+const MAX_CHUNK_DATA_LEN: usize = 64 * 1024 * 1024;
+
+pub struct ChunkReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R) -> Self {
+        ChunkReader { reader }
+    }
+
+    fn read_exact_or_none(&mut self, buf: &mut [u8]) -> Option<Result<(), String>> {
+        match self.reader.read_exact(buf) {
+            Ok(()) => Some(Ok(())),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => None,
+            Err(e) => Some(Err(e.to_string())),
+        }
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut length_buf = [0u8; 4];
+        match self.read_exact_or_none(&mut length_buf)? {
+            Ok(()) => {}
+            Err(msg) => return Some(Err(msg)),
+        }
+        let data_size = u32::from_be_bytes(length_buf) as usize;
+        if data_size > MAX_CHUNK_DATA_LEN {
+            return Some(Err(format!(
+                "Chunk data length {data_size} exceeds maximum of {MAX_CHUNK_DATA_LEN}"
+            )));
+        }
+
+        let mut chunk_type_buf = [0u8; 4];
+        if let Err(e) = self.reader.read_exact(&mut chunk_type_buf) {
+            return Some(Err(e.to_string()));
+        }
+        let chunk_type = match ChunkType::try_from(chunk_type_buf) {
+            Ok(chunk_type) => chunk_type,
+            Err(msg) => return Some(Err(msg)),
+        };
+
+        let mut chunk_data = vec![0u8; data_size];
+        if let Err(e) = self.reader.read_exact(&mut chunk_data) {
+            return Some(Err(e.to_string()));
+        }
+
+        let mut crc_buf = [0u8; 4];
+        if let Err(e) = self.reader.read_exact(&mut crc_buf) {
+            return Some(Err(e.to_string()));
+        }
+        let crc = u32::from_be_bytes(crc_buf);
+
+        Some(Chunk::from_parts(chunk_type, chunk_data, crc))
+    }
+}
+
+pub struct ChunkWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> ChunkWriter<W> {
+    pub fn new(writer: W) -> Self {
+        ChunkWriter { writer }
+    }
+
+    pub fn write_chunk(&mut self, chunk: &Chunk) -> Result<(), String> {
+        self.writer
+            .write_all(&chunk.as_bytes())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunk_bytes() -> Vec<u8> {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_one_chunk() {
+        let bytes = testing_chunk_bytes();
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        let chunk = reader.next().unwrap().unwrap();
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_multiple_chunks() {
+        let mut bytes = testing_chunk_bytes();
+        bytes.extend(testing_chunk_bytes());
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_reports_invalid_crc() {
+        let mut bytes = testing_chunk_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_reports_truncated_chunk() {
+        let bytes = testing_chunk_bytes();
+        let mut reader = ChunkReader::new(&bytes[..bytes.len() - 2]);
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_rejects_oversized_length_before_allocating() {
+        let mut length_buf = ((MAX_CHUNK_DATA_LEN + 1) as u32).to_be_bytes().to_vec();
+        length_buf.extend("RuSt".as_bytes());
+        let mut reader = ChunkReader::new(length_buf.as_slice());
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_chunk_writer_round_trips_through_reader() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = "This is where your secret message will be!"
+            .as_bytes()
+            .to_vec();
+        let chunk = Chunk::new(chunk_type, data);
+
+        let mut buf = Vec::new();
+        ChunkWriter::new(&mut buf).write_chunk(&chunk).unwrap();
+
+        let mut reader = ChunkReader::new(buf.as_slice());
+        let read_back = reader.next().unwrap().unwrap();
+        assert_eq!(read_back.crc(), chunk.crc());
+        assert_eq!(read_back.data_as_string().unwrap(), "This is where your secret message will be!");
+    }
+}
@@ -0,0 +1,80 @@
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use rand::RngCore;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; KEY_LEN], String> {
+    let mut key = [0u8; KEY_LEN];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+pub fn encrypt(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new((&key).into());
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|e| e.to_string())?;
+
+    let mut payload = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(&salt);
+    payload.extend_from_slice(&nonce_bytes);
+    payload.extend_from_slice(&ciphertext);
+    Ok(payload)
+}
+
+pub fn decrypt(payload: &[u8], passphrase: &str) -> Result<Vec<u8>, String> {
+    if payload.len() < SALT_LEN + NONCE_LEN {
+        return Err("Encrypted payload is too short".into());
+    }
+    let (salt, rest) = payload.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_key(passphrase, salt)?;
+    let cipher = ChaCha20Poly1305::new((&key).into());
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    cipher.decrypt(nonce, ciphertext).map_err(|_| {
+        "Failed to decrypt chunk payload: wrong passphrase or tampered data".to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let plaintext = b"This is where your secret message will be!";
+        let payload = encrypt(plaintext, "correct horse battery staple").unwrap();
+        let decrypted = decrypt(&payload, "correct horse battery staple").unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_passphrase() {
+        let payload = encrypt(b"secret", "passphrase-a").unwrap();
+        assert!(decrypt(&payload, "passphrase-b").is_err());
+    }
+
+    #[test]
+    fn test_decrypt_rejects_tampered_payload() {
+        let mut payload = encrypt(b"secret", "passphrase").unwrap();
+        let last = payload.len() - 1;
+        payload[last] ^= 0xff;
+        assert!(decrypt(&payload, "passphrase").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod chunk;
+pub mod chunk_io;
+pub mod chunk_type;
+mod crypto;
+pub mod signature;
@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+use secp256k1::ecdsa::Signature;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+use sha2::{Digest, Sha256};
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+pub const SIGNATURE_CHUNK_TYPE: &str = "sTKn";
+
+fn canonical_hash(chunk: &Chunk) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(chunk.length().to_be_bytes());
+    hasher.update(chunk.chunk_type().bytes());
+    hasher.update(chunk.data());
+    hasher.finalize().into()
+}
+
+pub fn sign(chunk: &Chunk, secret_key: &SecretKey) -> [u8; 64] {
+    let secp = Secp256k1::signing_only();
+    let message = Message::from_digest(canonical_hash(chunk));
+    secp.sign_ecdsa(&message, secret_key).serialize_compact()
+}
+
+pub fn verify(chunk: &Chunk, signature: &[u8; 64], public_key: &PublicKey) -> Result<(), String> {
+    let secp = Secp256k1::verification_only();
+    let message = Message::from_digest(canonical_hash(chunk));
+    let signature = Signature::from_compact(signature).map_err(|e| e.to_string())?;
+    secp.verify_ecdsa(&message, &signature, public_key)
+        .map_err(|_| "Signature verification failed: chunk data does not match signature".to_string())
+}
+
+pub fn signature_chunk(signature: &[u8; 64]) -> Chunk {
+    let chunk_type = ChunkType::from_str(SIGNATURE_CHUNK_TYPE).unwrap();
+    Chunk::new(chunk_type, signature.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keypair() -> (SecretKey, PublicKey) {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x11; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+        (secret_key, public_key)
+    }
+
+    #[test]
+    fn test_sign_and_verify_round_trip() {
+        let (secret_key, public_key) = keypair();
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, b"This is where your secret message will be!".to_vec());
+
+        let signature = chunk.sign(&secret_key);
+        assert!(chunk.verify(&signature, &public_key).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_chunk() {
+        let (secret_key, public_key) = keypair();
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, b"original message".to_vec());
+        let signature = chunk.sign(&secret_key);
+
+        let tampered_type = ChunkType::from_str("RuSt").unwrap();
+        let tampered = Chunk::new(tampered_type, b"forged message!!!".to_vec());
+        assert!(tampered.verify(&signature, &public_key).is_err());
+    }
+
+    #[test]
+    fn test_signature_chunk_embeds_signature_bytes() {
+        let (secret_key, _public_key) = keypair();
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, b"secret".to_vec());
+        let signature = chunk.sign(&secret_key);
+
+        let sig_chunk = Chunk::signature_chunk(&signature);
+        assert_eq!(sig_chunk.chunk_type().to_string(), SIGNATURE_CHUNK_TYPE);
+        assert!(sig_chunk.chunk_type().is_reserved_bit_valid());
+        assert_eq!(sig_chunk.data(), signature.as_slice());
+    }
+}